@@ -0,0 +1,59 @@
+use glam::Vec3;
+
+/// Approximates the RGB color of a blackbody radiator at `kelvin`
+/// temperature using Tanner Helland's widely used polynomial fit to the
+/// Planckian locus, normalised to `[0, 1]`.
+pub fn color(kelvin: f32) -> Vec3 {
+    let temp = (kelvin / 100.0).clamp(10.0, 400.0);
+
+    let red = if temp <= 66.0 {
+        255.0
+    } else {
+        (329.698_73 * (temp - 60.0).powf(-0.133_204_76)).clamp(0.0, 255.0)
+    };
+
+    let green = if temp <= 66.0 {
+        (99.470_80 * temp.ln() - 161.119_57).clamp(0.0, 255.0)
+    } else {
+        (288.122_17 * (temp - 60.0).powf(-0.075_514_85)).clamp(0.0, 255.0)
+    };
+
+    let blue = if temp >= 66.0 {
+        255.0
+    } else if temp <= 19.0 {
+        0.0
+    } else {
+        (138.517_73 * (temp - 10.0).ln() - 305.044_8).clamp(0.0, 255.0)
+    };
+
+    Vec3::new(red, green, blue) / 255.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Blue content should rise monotonically with temperature (cool objects
+    /// are red/orange, hot ones are blue-white), across the fit's full range.
+    #[test]
+    fn blue_channel_increases_with_temperature() {
+        let cool = color(1_000.0);
+        let warm = color(6_500.0);
+        let hot = color(20_000.0);
+        let very_hot = color(40_000.0);
+
+        assert!(cool.z <= warm.z);
+        assert!(warm.z <= hot.z);
+        assert!(hot.z <= very_hot.z);
+    }
+
+    #[test]
+    fn colors_stay_within_unit_range() {
+        for kelvin in [100.0, 1_000.0, 6_500.0, 15_000.0, 40_000.0, 100_000.0] {
+            let c = color(kelvin);
+            assert!(c.x >= 0.0 && c.x <= 1.0);
+            assert!(c.y >= 0.0 && c.y <= 1.0);
+            assert!(c.z >= 0.0 && c.z <= 1.0);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use glam::Vec3;
+
+const LUMINANCE_WEIGHTS: Vec3 = Vec3::new(0.2126, 0.7152, 0.0722);
+
+/// Extracts pixels above `threshold` luminance, blurs them with a separable
+/// Gaussian (horizontal then vertical), and adds the blurred glow back onto
+/// `hdr` scaled by `intensity`.
+pub fn apply(hdr: &[Vec3], width: usize, height: usize, threshold: f32, radius: usize, intensity: f32) -> Vec<Vec3> {
+    if radius == 0 || intensity <= 0.0 {
+        return hdr.to_vec();
+    }
+
+    let bright: Vec<Vec3> = hdr
+        .iter()
+        .map(|&c| if c.dot(LUMINANCE_WEIGHTS) > threshold { c } else { Vec3::ZERO })
+        .collect();
+
+    let kernel = gaussian_kernel(radius);
+    let blurred_horizontal = blur_pass(&bright, width, height, &kernel, true);
+    let blurred = blur_pass(&blurred_horizontal, width, height, &kernel, false);
+
+    hdr.iter()
+        .zip(blurred.iter())
+        .map(|(&base, &glow)| base + glow * intensity)
+        .collect()
+}
+
+/// Normalised Gaussian weights for offsets `-radius..=radius`, indexed as
+/// `kernel[radius + offset]`.
+fn gaussian_kernel(radius: usize) -> Vec<f32> {
+    let sigma = (radius as f32 / 2.0).max(0.5);
+    let weights: Vec<f32> = (-(radius as isize)..=radius as isize)
+        .map(|offset| (-((offset * offset) as f32) / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let sum: f32 = weights.iter().sum();
+    weights.into_iter().map(|w| w / sum).collect()
+}
+
+/// Runs one 1D Gaussian pass, horizontal when `is_horizontal` else vertical.
+fn blur_pass(input: &[Vec3], width: usize, height: usize, kernel: &[f32], is_horizontal: bool) -> Vec<Vec3> {
+    let radius = kernel.len() / 2;
+    let mut output = vec![Vec3::ZERO; input.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = Vec3::ZERO;
+            for (k, &weight) in kernel.iter().enumerate() {
+                let offset = k as isize - radius as isize;
+                let (sx, sy) = if is_horizontal {
+                    ((x as isize + offset).clamp(0, width as isize - 1), y as isize)
+                } else {
+                    (x as isize, (y as isize + offset).clamp(0, height as isize - 1))
+                };
+                sum += input[sy as usize * width + sx as usize] * weight;
+            }
+            output[y * width + x] = sum;
+        }
+    }
+
+    output
+}
@@ -0,0 +1,229 @@
+use glam::Vec3;
+
+use crate::mesh::Triangle;
+
+/// An axis-aligned bounding box.
+#[derive(Clone, Copy)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    fn empty() -> Self {
+        Self {
+            min: Vec3::splat(f32::INFINITY),
+            max: Vec3::splat(f32::NEG_INFINITY),
+        }
+    }
+
+    fn union_point(mut self, p: Vec3) -> Self {
+        self.min = self.min.min(p);
+        self.max = self.max.max(p);
+        self
+    }
+
+    fn union(self, other: &Aabb) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    fn centroid(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Slab test against a ray parameterised as `origin + t * dir` for
+    /// `t` in `[0, t_max]`.
+    fn intersects_ray(&self, origin: Vec3, inv_dir: Vec3, t_max: f32) -> bool {
+        let t0 = (self.min - origin) * inv_dir;
+        let t1 = (self.max - origin) * inv_dir;
+        let t_min = t0.min(t1);
+        let t_max_axis = t0.max(t1);
+        let t_enter = t_min.x.max(t_min.y).max(t_min.z).max(0.0);
+        let t_exit = t_max_axis.x.min(t_max_axis.y).min(t_max_axis.z).min(t_max);
+        t_enter <= t_exit
+    }
+}
+
+enum Node {
+    Leaf {
+        bounds: Aabb,
+        triangle_indices: Vec<usize>,
+    },
+    Interior {
+        bounds: Aabb,
+        left: Box<Node>,
+        right: Box<Node>,
+    },
+}
+
+impl Node {
+    fn bounds(&self) -> &Aabb {
+        match self {
+            Node::Leaf { bounds, .. } => bounds,
+            Node::Interior { bounds, .. } => bounds,
+        }
+    }
+}
+
+/// A bounding-volume hierarchy over a mesh's triangles, built with a simple
+/// median split (good enough for interactive triangle counts without the
+/// complexity of a full SAH builder).
+pub struct Bvh {
+    root: Node,
+}
+
+const LEAF_SIZE: usize = 4;
+
+impl Bvh {
+    pub fn build(triangles: &[Triangle]) -> Self {
+        let mut indices: Vec<usize> = (0..triangles.len()).collect();
+        let root = Self::build_node(triangles, &mut indices);
+        Self { root }
+    }
+
+    fn build_node(triangles: &[Triangle], indices: &mut [usize]) -> Node {
+        let bounds = indices
+            .iter()
+            .fold(Aabb::empty(), |acc, &i| acc.union(&triangles[i].aabb()));
+
+        if indices.len() <= LEAF_SIZE {
+            return Node::Leaf {
+                bounds,
+                triangle_indices: indices.to_vec(),
+            };
+        }
+
+        let extent = bounds.max - bounds.min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+
+        indices.sort_by(|&a, &b| {
+            let ca = triangles[a].aabb().centroid()[axis];
+            let cb = triangles[b].aabb().centroid()[axis];
+            ca.partial_cmp(&cb).unwrap()
+        });
+
+        let mid = indices.len() / 2;
+        let (left_indices, right_indices) = indices.split_at_mut(mid);
+        let left = Self::build_node(triangles, left_indices);
+        let right = Self::build_node(triangles, right_indices);
+
+        Node::Interior {
+            bounds,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Finds the closest triangle intersection along the segment from
+    /// `start` to `end`, returning the distance along that segment
+    /// (`[0, 1]`), the triangle, and the hit normal.
+    pub fn intersect_segment<'a>(
+        &self,
+        triangles: &'a [Triangle],
+        start: Vec3,
+        end: Vec3,
+    ) -> Option<(f32, &'a Triangle, Vec3)> {
+        let dir = end - start;
+        let len = dir.length();
+        if len < 1e-9 {
+            return None;
+        }
+        let dir_n = dir / len;
+        let inv_dir = Vec3::ONE / dir_n;
+
+        let mut best: Option<(f32, &Triangle, Vec3)> = None;
+        self.intersect_node(&self.root, triangles, start, dir_n, inv_dir, len, &mut best);
+        best.map(|(t, tri, n)| (t / len, tri, n))
+    }
+
+    fn intersect_node<'a>(
+        &self,
+        node: &Node,
+        triangles: &'a [Triangle],
+        origin: Vec3,
+        dir: Vec3,
+        inv_dir: Vec3,
+        t_max: f32,
+        best: &mut Option<(f32, &'a Triangle, Vec3)>,
+    ) {
+        let current_max = best.as_ref().map(|(t, ..)| *t).unwrap_or(t_max);
+        if !node.bounds().intersects_ray(origin, inv_dir, current_max) {
+            return;
+        }
+
+        match node {
+            Node::Leaf { triangle_indices, .. } => {
+                for &i in triangle_indices {
+                    let tri = &triangles[i];
+                    if let Some((t, normal)) = tri.intersect(origin, dir, current_max) {
+                        if best.as_ref().map_or(true, |(best_t, ..)| t < *best_t) {
+                            *best = Some((t, tri, normal));
+                        }
+                    }
+                }
+            }
+            Node::Interior { left, right, .. } => {
+                self.intersect_node(left, triangles, origin, dir, inv_dir, t_max, best);
+                self.intersect_node(right, triangles, origin, dir, inv_dir, t_max, best);
+            }
+        }
+    }
+}
+
+impl Triangle {
+    pub(crate) fn aabb(&self) -> Aabb {
+        Aabb::empty()
+            .union_point(self.v0)
+            .union_point(self.v1)
+            .union_point(self.v2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::material::Material;
+
+    fn triangle_at(x_offset: f32) -> Triangle {
+        Triangle {
+            v0: Vec3::new(x_offset - 1.0, 0.0, -1.0),
+            v1: Vec3::new(x_offset + 1.0, 0.0, -1.0),
+            v2: Vec3::new(x_offset, 0.0, 1.0),
+            material: Material::Lambertian {
+                albedo: Vec3::splat(0.8),
+            },
+        }
+    }
+
+    #[test]
+    fn finds_closest_triangle_among_many() {
+        let triangles: Vec<Triangle> = (0..20).map(|i| triangle_at(i as f32 * 10.0)).collect();
+        let bvh = Bvh::build(&triangles);
+
+        let start = Vec3::new(0.0, 5.0, 0.0);
+        let end = Vec3::new(0.0, -5.0, 0.0);
+        let hit = bvh.intersect_segment(&triangles, start, end);
+        let (t, tri, _normal) = hit.expect("segment should cross the nearest triangle");
+        assert!((t - 0.5).abs() < 1e-4);
+        assert!((tri.centroid().x).abs() < 1e-4);
+    }
+
+    #[test]
+    fn segment_missing_all_triangles_is_none() {
+        let triangles: Vec<Triangle> = (0..20).map(|i| triangle_at(i as f32 * 10.0)).collect();
+        let bvh = Bvh::build(&triangles);
+
+        let start = Vec3::new(500.0, 5.0, 0.0);
+        let end = Vec3::new(500.0, -5.0, 0.0);
+        assert!(bvh.intersect_segment(&triangles, start, end).is_none());
+    }
+}
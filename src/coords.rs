@@ -0,0 +1,100 @@
+use glam::{DVec3, Vec3};
+
+/// An integer grid cell in the floating-origin coordinate scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridCell {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl GridCell {
+    pub const ORIGIN: GridCell = GridCell { x: 0, y: 0, z: 0 };
+}
+
+/// A world-space position stored as an integer cell plus an `f32` offset
+/// within that cell.
+///
+/// `f32` alone loses positional precision badly past roughly `10^6` units,
+/// which matters once a scene's disk or orbiting bodies are placed at
+/// astronomical distances. Splitting the coordinate into a coarse `i64`
+/// cell index and a small `f32` offset keeps the offset's magnitude (and so
+/// its precision) bounded no matter how far the cell is from the origin.
+#[derive(Debug, Clone, Copy)]
+pub struct WorldPosition {
+    pub cell: GridCell,
+    pub offset: Vec3,
+}
+
+impl WorldPosition {
+    pub const ORIGIN: WorldPosition = WorldPosition {
+        cell: GridCell::ORIGIN,
+        offset: Vec3::ZERO,
+    };
+
+    /// Buckets an absolute double-precision position into a cell plus a
+    /// small offset, given the edge length of one cell.
+    pub fn from_dvec3(position: DVec3, cell_size: f64) -> Self {
+        let cell = GridCell {
+            x: (position.x / cell_size).floor() as i64,
+            y: (position.y / cell_size).floor() as i64,
+            z: (position.z / cell_size).floor() as i64,
+        };
+        let cell_origin = DVec3::new(
+            cell.x as f64 * cell_size,
+            cell.y as f64 * cell_size,
+            cell.z as f64 * cell_size,
+        );
+        Self {
+            cell,
+            offset: (position - cell_origin).as_vec3(),
+        }
+    }
+
+    /// This position expressed as an `f32` offset relative to `origin`,
+    /// rebasing across however many cells separate them. This is the only
+    /// point `f32` precision matters: the *difference* between two nearby
+    /// positions, not their absolute distance from the universe's origin.
+    pub fn relative_to(&self, origin: &WorldPosition, cell_size: f64) -> Vec3 {
+        let cell_delta = DVec3::new(
+            (self.cell.x - origin.cell.x) as f64 * cell_size,
+            (self.cell.y - origin.cell.y) as f64 * cell_size,
+            (self.cell.z - origin.cell.z) as f64 * cell_size,
+        );
+        let offset_delta = (self.offset - origin.offset).as_dvec3();
+        (cell_delta + offset_delta).as_vec3()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_to_matches_naive_f64_difference_within_one_cell() {
+        let cell_size = 1.0e4;
+        let a = WorldPosition::from_dvec3(DVec3::new(123.0, 45.0, 6789.0), cell_size);
+        let b = WorldPosition::from_dvec3(DVec3::new(321.0, 900.0, 10.0), cell_size);
+
+        let rebased = a.relative_to(&b, cell_size);
+        let naive = DVec3::new(123.0, 45.0, 6789.0) - DVec3::new(321.0, 900.0, 10.0);
+
+        assert!((rebased.as_dvec3() - naive).length() < 1e-2);
+    }
+
+    #[test]
+    fn relative_to_matches_naive_f64_difference_across_cell_boundary() {
+        let cell_size = 1.0e4;
+        let absolute_a = DVec3::new(2.5e7, 1.0e6, -3.0e7);
+        let absolute_b = DVec3::new(-4.2e7, 2.0e6, 1.0e7);
+        let a = WorldPosition::from_dvec3(absolute_a, cell_size);
+        let b = WorldPosition::from_dvec3(absolute_b, cell_size);
+
+        assert_ne!(a.cell, b.cell);
+
+        let rebased = a.relative_to(&b, cell_size);
+        let naive = absolute_a - absolute_b;
+
+        assert!((rebased.as_dvec3() - naive).length() / naive.length() < 1e-4);
+    }
+}
@@ -0,0 +1,132 @@
+use glam::Vec3;
+
+/// How a traced photon geodesic ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeodesicEnd {
+    /// The photon crossed the Schwarzschild radius (`u >= 1/r_s`).
+    Captured,
+    /// The photon's orbit became unbound (`u <= 0`) and it escaped to infinity.
+    Escaped,
+}
+
+/// Integrates photon geodesics in the equatorial plane of a Schwarzschild
+/// black hole.
+///
+/// Uses the standard substitution `u = 1/r` as a function of the orbital
+/// azimuth `φ`, which obeys `d²u/dφ² = -u + (3/2)·r_s·u²` in geometric units.
+/// Stepping in `φ` with a fixed-size classic RK4 gives correct deflection
+/// angles and photon-ring structure, unlike a naive force integrator stepped
+/// in affine/coordinate distance.
+pub struct GeodesicTracer {
+    pub schwarzschild_radius: f32,
+    pub steps: usize,
+    pub dphi: f32,
+}
+
+impl GeodesicTracer {
+    /// Traces a single photon from `origin` along `direction`, returning the
+    /// sequence of world-space positions visited (in order, starting near
+    /// `origin`) and how the geodesic terminated.
+    ///
+    /// `direction` need not be tangent to the orbital plane; the plane is
+    /// derived from `origin` and `direction` so any ray can be traced.
+    pub fn trace(&self, origin: Vec3, direction: Vec3) -> (Vec<Vec3>, GeodesicEnd) {
+        let r0 = origin.length();
+        let e_r = origin / r0;
+        let dir = direction.normalize();
+
+        // Basis of the orbital plane: e_r (radial) and e_perp (tangential,
+        // chosen so that phi increases in the direction of travel).
+        let plane_normal = e_r.cross(dir);
+        let e_perp = if plane_normal.length_squared() < 1e-12 {
+            // Direction is (anti-)radial: pick an arbitrary perpendicular axis,
+            // the orbit is degenerate (zero angular momentum) either way.
+            e_r.cross(Vec3::Y).try_normalize().unwrap_or(Vec3::X)
+        } else {
+            plane_normal.normalize().cross(e_r).normalize()
+        };
+
+        let u0 = 1.0 / r0;
+        let cos_psi = dir.dot(e_r).clamp(-1.0, 1.0);
+        let sin_psi = dir.dot(e_perp);
+        // psi is the angle between the outward radial direction and the ray;
+        // w0 = du/dphi at the origin follows from differentiating u = 1/r
+        // along the ray's initial direction.
+        let w0 = if sin_psi.abs() < 1e-6 {
+            0.0
+        } else {
+            -u0 * cos_psi / sin_psi
+        };
+
+        let mut u = u0;
+        let mut w = w0;
+        let mut phi = 0.0_f32;
+        let rs = self.schwarzschild_radius;
+        let inv_rs = 1.0 / rs;
+
+        let f = |u: f32, w: f32| -> (f32, f32) { (w, -u + 1.5 * rs * u * u) };
+
+        let mut path = Vec::with_capacity(self.steps);
+        let mut end = GeodesicEnd::Escaped;
+
+        for _ in 0..self.steps {
+            let (k1u, k1w) = f(u, w);
+            let (k2u, k2w) = f(u + 0.5 * self.dphi * k1u, w + 0.5 * self.dphi * k1w);
+            let (k3u, k3w) = f(u + 0.5 * self.dphi * k2u, w + 0.5 * self.dphi * k2w);
+            let (k4u, k4w) = f(u + self.dphi * k3u, w + self.dphi * k3w);
+
+            u += self.dphi / 6.0 * (k1u + 2.0 * k2u + 2.0 * k3u + k4u);
+            w += self.dphi / 6.0 * (k1w + 2.0 * k2w + 2.0 * k3w + k4w);
+            phi += self.dphi;
+
+            if u >= inv_rs {
+                end = GeodesicEnd::Captured;
+                break;
+            }
+            if u <= 0.0 {
+                end = GeodesicEnd::Escaped;
+                break;
+            }
+
+            let r = 1.0 / u;
+            path.push(e_r * (r * phi.cos()) + e_perp * (r * phi.sin()));
+        }
+
+        (path, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A photon with a small impact parameter (well under the ~2.6·r_s
+    /// critical value) aimed almost straight at the hole plunges in.
+    #[test]
+    fn near_radial_low_impact_photon_is_captured() {
+        let tracer = GeodesicTracer {
+            schwarzschild_radius: 1.0,
+            steps: 2000,
+            dphi: 0.01,
+        };
+        let origin = Vec3::new(10.0, 0.0, 0.0);
+        let direction = Vec3::new(-0.99499, 0.0, 0.1);
+        let (_path, end) = tracer.trace(origin, direction);
+        assert_eq!(end, GeodesicEnd::Captured);
+    }
+
+    /// A photon aimed purely tangentially from far away has a huge impact
+    /// parameter and sails past the hole.
+    #[test]
+    fn tangential_photon_at_large_radius_escapes() {
+        let tracer = GeodesicTracer {
+            schwarzschild_radius: 1.0,
+            steps: 2000,
+            dphi: 0.01,
+        };
+        let origin = Vec3::new(50.0, 0.0, 0.0);
+        let direction = Vec3::new(0.0, 0.0, 1.0);
+        let (_path, end) = tracer.trace(origin, direction);
+        assert_eq!(end, GeodesicEnd::Escaped);
+    }
+}
@@ -0,0 +1,118 @@
+use glam::{vec2, vec3, Vec3};
+
+use crate::blackbody;
+use crate::material::Material;
+
+/// Record of a ray intersecting a surface.
+pub struct Hit {
+    pub point: Vec3,
+    pub normal: Vec3,
+    pub material: Material,
+}
+
+/// A surface that a traced ray can intersect.
+///
+/// The geodesic marcher advances in discrete steps, so intersection is
+/// tested per step against the straight-line segment from `start` to `end`
+/// rather than a single point; analytic surfaces like [`Disk`] and
+/// [`Sphere`] only need `end`, while [`crate::mesh::Mesh`] runs the segment
+/// through its BVH for a true Möller–Trumbore test.
+pub trait Hittable: Sync {
+    fn hit_segment(&self, start: Vec3, end: Vec3) -> Option<Hit>;
+}
+
+/// The accretion disk: a flat, glowing annulus in the `y = 0` plane.
+///
+/// Emission follows the Shakura–Sunyaev thin-disk scaling
+/// `T(r) ∝ r^(-3/4)`, converted to a color via the blackbody/Planckian
+/// locus approximation in [`blackbody`], with relativistic Doppler
+/// beaming applied on top: the approaching side brightens and blueshifts,
+/// the receding side dims and redshifts.
+pub struct Disk {
+    pub inner_radius: f32,
+    pub outer_radius: f32,
+    pub half_thickness: f32,
+    /// Temperature at `inner_radius`, in Kelvin.
+    pub inner_temperature: f32,
+    pub schwarzschild_radius: f32,
+    pub beaming_enabled: bool,
+}
+
+/// Emission is scaled well above 1.0 so the inner, hottest bands of the
+/// disk bloom.
+const HDR_SCALE: f32 = 3.0;
+
+impl Hittable for Disk {
+    fn hit_segment(&self, start: Vec3, end: Vec3) -> Option<Hit> {
+        if end.y.abs() > self.half_thickness {
+            return None;
+        }
+        let r = vec2(end.x, end.z).length();
+        if r <= self.inner_radius || r >= self.outer_radius {
+            return None;
+        }
+
+        let temperature = self.inner_temperature * (self.inner_radius / r).powf(0.75);
+
+        let doppler = if self.beaming_enabled {
+            let radial_dir = vec3(end.x, 0.0, end.z) / r;
+            let velocity_dir = Vec3::Y.cross(radial_dir).normalize();
+            // Keplerian orbital speed in geometric units (GM = r_s / 2, c = 1).
+            let beta = (self.schwarzschild_radius / (2.0 * r)).sqrt().min(0.999);
+            let gamma = 1.0 / (1.0 - beta * beta).sqrt();
+            // The march goes camera -> disk, so the photon's actual
+            // propagation direction (disk -> observer) is the reverse.
+            let photon_to_observer = (start - end).try_normalize().unwrap_or(Vec3::Z);
+            let cos_theta = velocity_dir.dot(photon_to_observer);
+            1.0 / (gamma * (1.0 - beta * cos_theta))
+        } else {
+            1.0
+        };
+
+        let observed_color = blackbody::color(temperature * doppler);
+        // Bolometric intensity scales with the fourth power of the Doppler
+        // factor.
+        let color = observed_color * doppler.powf(4.0) * HDR_SCALE;
+
+        Some(Hit {
+            point: end,
+            normal: Vec3::Y,
+            material: Material::Emissive { color },
+        })
+    }
+}
+
+/// A simple sphere, e.g. a probe or moon placed near the black hole.
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+    pub material: Material,
+}
+
+impl Hittable for Sphere {
+    fn hit_segment(&self, _start: Vec3, end: Vec3) -> Option<Hit> {
+        let offset = end - self.center;
+        if offset.length_squared() <= self.radius * self.radius {
+            Some(Hit {
+                point: end,
+                normal: offset.try_normalize().unwrap_or(Vec3::Y),
+                material: self.material,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// The set of objects a ray can hit, tested in order.
+pub struct Scene<'a> {
+    pub objects: Vec<&'a dyn Hittable>,
+}
+
+impl Scene<'_> {
+    pub fn hit_segment(&self, start: Vec3, end: Vec3) -> Option<Hit> {
+        self.objects
+            .iter()
+            .find_map(|object| object.hit_segment(start, end))
+    }
+}
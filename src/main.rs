@@ -1,5 +1,31 @@
+mod blackbody;
+mod bloom;
+mod bvh;
+mod coords;
+mod geodesic;
+mod hittable;
+mod material;
+mod mesh;
+mod renderer;
+
+use coords::WorldPosition;
 use eframe::egui;
-use glam::{vec2, vec3, Vec3};
+use geodesic::GeodesicTracer;
+use glam::{vec2, DVec3, Vec3};
+use hittable::{Disk, Hittable, Scene, Sphere};
+use material::Material;
+use mesh::Mesh;
+use rayon::prelude::*;
+use renderer::{render_frame, Camera, RenderSettings};
+
+/// Selects how a ray's path through curved spacetime is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntegrationMode {
+    /// The original ad-hoc inverse-square force nudge, fast but unphysical.
+    Newtonian,
+    /// Proper Schwarzschild null-geodesic integration (see [`geodesic`]).
+    Geodesic,
+}
 
 /// Stores the state of the application.
 struct BlackHoleApp {
@@ -11,15 +37,78 @@ struct BlackHoleApp {
     radius: f32,
     /// Camera's orbital angle.
     azimuth: f32,
+    /// Which ray integrator to use when marching through curved spacetime.
+    integration_mode: IntegrationMode,
+    /// Jittered rays averaged per pixel when path tracing (anti-aliasing).
+    samples_per_pixel: u32,
+    /// Maximum number of material bounces per path.
+    max_depth: u32,
+    /// Running radiance sum per pixel for progressive accumulation, in
+    /// Geodesic mode, while the camera is stationary.
+    accumulator: Vec<Vec3>,
+    /// Number of samples folded into `accumulator`, per pixel.
+    accumulated_samples: Vec<u32>,
+    /// `(radius, azimuth, schwarzschild_radius_km)` as of the last
+    /// accumulated frame; a change resets the accumulator.
+    prev_camera_state: Option<(f32, f32, f32)>,
+    /// Path typed into the model loader field.
+    mesh_path: String,
+    /// The currently loaded model, if any, placed near the black hole.
+    mesh: Option<Mesh>,
+    /// Error from the last failed `Mesh::load`, shown under the path field.
+    mesh_load_error: Option<String>,
+    /// Luminance above which a pixel contributes to the bloom glow.
+    bloom_threshold: f32,
+    /// Gaussian blur radius (in pixels) for the bloom glow.
+    bloom_radius: usize,
+    /// How strongly the blurred glow is added back onto the base image.
+    bloom_intensity: f32,
+    /// Edge length of one floating-origin grid cell, in kilometers.
+    cell_size: f64,
+    /// The black hole's real Schwarzschild radius, in kilometers. In Geodesic
+    /// mode this is the unit the tracer, disk, and companion sphere are
+    /// actually built in, so the camera's orbit and `cell_size` crossings
+    /// play out at real astronomical distances. The legacy Newtonian
+    /// fallback is tuned for `r_s = 1` and stays on that fixed scale
+    /// regardless of this control.
+    schwarzschild_radius_km: f32,
+    /// Accretion disk inner edge, in Schwarzschild radii.
+    disk_inner_radius: f32,
+    /// Accretion disk outer edge, in Schwarzschild radii.
+    disk_outer_radius: f32,
+    /// Whether the disk's emission includes relativistic Doppler beaming.
+    beaming_enabled: bool,
+    /// Whether a metal companion sphere is placed in the scene.
+    show_companion_sphere: bool,
 }
 
 impl Default for BlackHoleApp {
     fn default() -> Self {
         Self {
-            image: egui::ColorImage::new([300, 200], vec![egui::Color32::BLACK; 300 * 200]),
+            image: egui::ColorImage::new([300, 200], egui::Color32::BLACK),
             texture: None,
             radius: 15.0,
             azimuth: 0.0,
+            integration_mode: IntegrationMode::Geodesic,
+            samples_per_pixel: 8,
+            max_depth: 8,
+            accumulator: vec![Vec3::ZERO; 300 * 200],
+            accumulated_samples: vec![0; 300 * 200],
+            prev_camera_state: None,
+            mesh_path: String::new(),
+            mesh: None,
+            mesh_load_error: None,
+            bloom_threshold: 1.0,
+            bloom_radius: 4,
+            bloom_intensity: 0.6,
+            cell_size: 1.0e6,
+            // Roughly Sagittarius A*'s scale, so the default view already
+            // spans millions of kilometers and crosses grid cells.
+            schwarzschild_radius_km: 1.27e7,
+            disk_inner_radius: 1.5,
+            disk_outer_radius: 4.0,
+            beaming_enabled: true,
+            show_companion_sphere: true,
         }
     }
 }
@@ -28,9 +117,90 @@ impl eframe::App for BlackHoleApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::SidePanel::left("Controls").show(ctx, |ui| {
             ui.heading("Camera Controls");
-            ui.add(egui::Slider::new(&mut self.radius, 2.0..=50.0).text("Distance"));
+            ui.add(egui::Slider::new(&mut self.radius, 2.0..=500.0).text("Distance (r_s)"));
             ui.label("Drag the image to orbit the camera.");
             ui.separator();
+            ui.heading("Ray Integrator");
+            ui.radio_value(
+                &mut self.integration_mode,
+                IntegrationMode::Geodesic,
+                "Geodesic (RK4)",
+            );
+            ui.radio_value(
+                &mut self.integration_mode,
+                IntegrationMode::Newtonian,
+                "Newtonian (fast)",
+            );
+            ui.add_enabled(
+                self.integration_mode == IntegrationMode::Geodesic,
+                egui::Slider::new(&mut self.samples_per_pixel, 1..=64).text("Samples/pixel"),
+            );
+            ui.add_enabled(
+                self.integration_mode == IntegrationMode::Geodesic,
+                egui::Slider::new(&mut self.max_depth, 1..=16).text("Max bounce depth"),
+            );
+            ui.separator();
+            ui.heading("Model");
+            ui.horizontal(|ui| {
+                ui.text_edit_singleline(&mut self.mesh_path);
+                if ui.button("Load").clicked() {
+                    match Mesh::load(&self.mesh_path) {
+                        Ok(mesh) => {
+                            self.mesh = Some(mesh);
+                            self.mesh_load_error = None;
+                        }
+                        Err(err) => self.mesh_load_error = Some(err),
+                    }
+                }
+            });
+            if let Some(err) = &self.mesh_load_error {
+                ui.colored_label(egui::Color32::RED, err);
+            }
+            ui.separator();
+            ui.heading("Scale");
+            ui.add(
+                egui::Slider::new(&mut self.schwarzschild_radius_km, 1.0..=2.0e7)
+                    .logarithmic(true)
+                    .text("Schwarzschild radius (km)"),
+            );
+            ui.add(
+                egui::Slider::new(&mut self.cell_size, 1.0e2..=1.0e8)
+                    .logarithmic(true)
+                    .text("Grid cell size (km)"),
+            );
+            ui.label("Positions are stored relative to this grid in real kilometers, so astronomical scales stay precise.");
+            ui.separator();
+            ui.heading("Accretion Disk");
+            ui.add(
+                egui::Slider::new(&mut self.disk_inner_radius, 1.01..=10.0)
+                    .text("Inner radius (r_s)"),
+            );
+            ui.add(
+                egui::Slider::new(
+                    &mut self.disk_outer_radius,
+                    self.disk_inner_radius + 0.1..=20.0,
+                )
+                .text("Outer radius (r_s)"),
+            );
+            ui.checkbox(&mut self.beaming_enabled, "Relativistic Doppler beaming");
+            ui.separator();
+            ui.heading("Scene Objects");
+            ui.checkbox(&mut self.show_companion_sphere, "Metal companion sphere");
+            ui.separator();
+            ui.heading("Bloom");
+            ui.add_enabled(
+                self.integration_mode == IntegrationMode::Geodesic,
+                egui::Slider::new(&mut self.bloom_threshold, 0.0..=5.0).text("Threshold"),
+            );
+            ui.add_enabled(
+                self.integration_mode == IntegrationMode::Geodesic,
+                egui::Slider::new(&mut self.bloom_radius, 0..=16).text("Blur radius"),
+            );
+            ui.add_enabled(
+                self.integration_mode == IntegrationMode::Geodesic,
+                egui::Slider::new(&mut self.bloom_intensity, 0.0..=2.0).text("Intensity"),
+            );
+            ui.separator();
             ui.heading("About");
             ui.label("A CPU-based black hole ray tracer using egui.");
         });
@@ -69,57 +239,199 @@ impl BlackHoleApp {
         let height = self.image.height();
         let aspect_ratio = width as f32 / height as f32;
 
-        let cam_pos = vec3(self.radius * self.azimuth.cos(), 3.0, self.radius * self.azimuth.sin());
+        // The black hole sits at the floating-origin's reference cell. The
+        // camera's absolute position is computed in real kilometers (orbit
+        // radius in `r_s` multiples times the real `schwarzschild_radius_km`)
+        // and rebased relative to the hole's cell, so the grid genuinely
+        // crosses cell boundaries at astronomical scales. Geodesic mode's
+        // tracer, disk, and companion sphere are all built in these same
+        // real kilometers, so the control actually changes what's rendered.
+        let world_scale_km = self.schwarzschild_radius_km as f64;
+        let black_hole_world = WorldPosition::ORIGIN;
+        let camera_world = WorldPosition::from_dvec3(
+            DVec3::new(
+                self.radius as f64 * self.azimuth.cos() as f64,
+                3.0,
+                self.radius as f64 * self.azimuth.sin() as f64,
+            ) * world_scale_km,
+            self.cell_size,
+        );
+        let cam_pos_km = camera_world.relative_to(&black_hole_world, self.cell_size);
+        // Direction vectors only depend on the camera's bearing toward the
+        // origin, not the absolute scale, so both modes share these.
         let look_at = Vec3::ZERO;
-        let forward = (look_at - cam_pos).normalize();
+        let forward = (look_at - cam_pos_km).normalize();
         let right = forward.cross(Vec3::Y).normalize() * aspect_ratio;
         let up = right.cross(forward);
 
-        let schwarzschild_radius: f32 = 1.0;
-        let sr_squared = schwarzschild_radius * schwarzschild_radius;
-        
-        for y in 0..height {
-            for x in 0..width {
-                let u = (x as f32 / width as f32) * 2.0 - 1.0;
-                let v = (y as f32 / height as f32) * 2.0 - 1.0;
+        let schwarzschild_radius = self.schwarzschild_radius_km;
+        let tracer = GeodesicTracer {
+            schwarzschild_radius,
+            steps: 256,
+            dphi: 0.015,
+        };
+        let disk = Disk {
+            inner_radius: schwarzschild_radius * self.disk_inner_radius,
+            outer_radius: schwarzschild_radius * self.disk_outer_radius,
+            half_thickness: schwarzschild_radius * 0.1,
+            inner_temperature: 20_000.0,
+            schwarzschild_radius,
+            beaming_enabled: self.beaming_enabled,
+        };
+        let companion_sphere = Sphere {
+            center: Vec3::new(0.0, schwarzschild_radius * 1.2, schwarzschild_radius * 5.0),
+            radius: schwarzschild_radius * 0.6,
+            material: Material::Metal {
+                albedo: Vec3::splat(0.9),
+                fuzz: 0.05,
+            },
+        };
 
-                let mut ray_dir = (forward + right * u - up * v).normalize();
-                let mut ray_pos = cam_pos;
+        let mut objects: Vec<&dyn Hittable> = vec![&disk];
+        if self.show_companion_sphere {
+            objects.push(&companion_sphere);
+        }
+        if let Some(mesh) = &self.mesh {
+            objects.push(mesh);
+        }
+        let scene = Scene { objects };
 
-                let mut final_color = egui::Color32::BLACK;
+        match self.integration_mode {
+            IntegrationMode::Newtonian => {
+                // The ad-hoc Newtonian fallback is tuned for r_s = 1 (its
+                // gravity and step constants don't scale), so it stays on
+                // that fixed, normalized scale regardless of the real
+                // Schwarzschild-radius control above.
+                let newtonian_rs: f32 = 1.0;
+                let sr_squared = newtonian_rs * newtonian_rs;
+                let newtonian_cam_pos = cam_pos_km / schwarzschild_radius;
 
-                for _ in 0..64 {
-                    let dist_sq = ray_pos.length_squared();
-                    let gravity = -ray_pos.normalize() * (1.0 / dist_sq) * 2.5;
-                    ray_dir = (ray_dir + gravity).normalize();
+                let mut pixels = vec![egui::Color32::BLACK; width * height];
+                pixels.par_chunks_mut(width).enumerate().for_each(|(y, row)| {
+                    for (x, pixel) in row.iter_mut().enumerate() {
+                        let u = (x as f32 / width as f32) * 2.0 - 1.0;
+                        let v = (y as f32 / height as f32) * 2.0 - 1.0;
+                        let ray_dir = (forward + right * u - up * v).normalize();
+                        *pixel = Self::trace_newtonian(newtonian_cam_pos, ray_dir, newtonian_rs, sr_squared);
+                    }
+                });
+                self.image = egui::ColorImage {
+                    size: [width, height],
+                    pixels,
+                };
+            }
+            IntegrationMode::Geodesic => {
+                let camera_state = (self.radius, self.azimuth, self.schwarzschild_radius_km);
+                if self.prev_camera_state != Some(camera_state) || self.accumulator.len() != width * height {
+                    self.accumulator = vec![Vec3::ZERO; width * height];
+                    self.accumulated_samples = vec![0; width * height];
+                    self.prev_camera_state = Some(camera_state);
+                }
 
-                    ray_pos += ray_dir * 0.5;
+                let settings = RenderSettings {
+                    camera: Camera {
+                        position: cam_pos_km,
+                        forward,
+                        right,
+                        up,
+                        width,
+                        height,
+                    },
+                    tracer: &tracer,
+                    scene: &scene,
+                    samples_per_pixel: self.samples_per_pixel,
+                    max_depth: self.max_depth,
+                };
 
-                    if ray_pos.length_squared() < sr_squared {
-                        final_color = egui::Color32::BLACK;
-                        break;
-                    }
+                let mut batch = vec![Vec3::ZERO; width * height];
+                render_frame(&settings, &mut batch);
 
-                    if ray_pos.y.abs() < 0.1 {
-                        let dist_from_center = vec2(ray_pos.x, ray_pos.z).length();
-                        if dist_from_center > schwarzschild_radius * 1.5 && dist_from_center < schwarzschild_radius * 4.0 {
-                            let pattern = ((dist_from_center * 5.0).sin() + 1.0) * 0.5;
-                            final_color = egui::Color32::from_rgb((255.0 * pattern) as u8, (120.0 * pattern) as u8, 0);
-                            break;
-                        }
-                    }
+                let hdr_frame: Vec<Vec3> = batch
+                    .into_iter()
+                    .zip(self.accumulator.iter_mut())
+                    .zip(self.accumulated_samples.iter_mut())
+                    .map(|((batch_radiance, accumulated), samples)| {
+                        *accumulated += batch_radiance * self.samples_per_pixel as f32;
+                        *samples += self.samples_per_pixel;
+                        *accumulated / (*samples).max(1) as f32
+                    })
+                    .collect();
 
-                    if ray_pos.length() > 60.0 {
-                        let star_val = (ray_dir.x.sin() * ray_dir.z.sin()).abs().powf(10.0);
-                        if star_val > 0.5 {
-                             final_color = egui::Color32::WHITE;
-                        }
-                        break;
-                    }
+                let bloomed = bloom::apply(
+                    &hdr_frame,
+                    width,
+                    height,
+                    self.bloom_threshold,
+                    self.bloom_radius,
+                    self.bloom_intensity,
+                );
+                let pixels = bloomed.into_iter().map(Self::tonemap).collect();
+
+                self.image = egui::ColorImage {
+                    size: [width, height],
+                    pixels,
+                };
+            }
+        }
+    }
+
+    /// Reinhard tonemaps (`c/(1+c)`) unbounded HDR radiance down to `[0, 1]`,
+    /// then applies gamma-2 (`sqrt`) correction and packs it into a `Color32`.
+    fn tonemap(radiance: Vec3) -> egui::Color32 {
+        let hdr = radiance.max(Vec3::ZERO);
+        let mapped = hdr / (Vec3::ONE + hdr);
+        let gamma = mapped.powf(0.5);
+        egui::Color32::from_rgb(
+            (gamma.x * 255.0) as u8,
+            (gamma.y * 255.0) as u8,
+            (gamma.z * 255.0) as u8,
+        )
+    }
+
+    /// The original ad-hoc inverse-square force integrator, kept as a fast
+    /// (but unphysical) fallback.
+    fn trace_newtonian(
+        cam_pos: Vec3,
+        mut ray_dir: Vec3,
+        schwarzschild_radius: f32,
+        sr_squared: f32,
+    ) -> egui::Color32 {
+        let mut ray_pos = cam_pos;
+
+        for _ in 0..64 {
+            let dist_sq = ray_pos.length_squared();
+            let gravity = -ray_pos.normalize() * (1.0 / dist_sq) * 2.5;
+            ray_dir = (ray_dir + gravity).normalize();
+
+            ray_pos += ray_dir * 0.5;
+
+            if ray_pos.length_squared() < sr_squared {
+                return egui::Color32::BLACK;
+            }
+
+            if ray_pos.y.abs() < 0.1 {
+                let dist_from_center = vec2(ray_pos.x, ray_pos.z).length();
+                if dist_from_center > schwarzschild_radius * 1.5
+                    && dist_from_center < schwarzschild_radius * 4.0
+                {
+                    let pattern = ((dist_from_center * 5.0).sin() + 1.0) * 0.5;
+                    return egui::Color32::from_rgb(
+                        (255.0 * pattern) as u8,
+                        (120.0 * pattern) as u8,
+                        0,
+                    );
+                }
+            }
+
+            if ray_pos.length() > 60.0 {
+                let star_val = (ray_dir.x.sin() * ray_dir.z.sin()).abs().powf(10.0);
+                if star_val > 0.5 {
+                    return egui::Color32::WHITE;
                 }
-                self.image[(x, y)] = final_color;
+                break;
             }
         }
+        egui::Color32::BLACK
     }
 }
 
@@ -0,0 +1,125 @@
+use glam::Vec3;
+use rand::Rng;
+
+/// How a surface responds to an incoming ray.
+#[derive(Debug, Clone, Copy)]
+pub enum Material {
+    /// Diffuse scattering: reflects with a cosine-weighted distribution
+    /// around the normal, attenuated by `albedo`.
+    Lambertian { albedo: Vec3 },
+    /// Mirror-like reflection, randomised by `fuzz` (0 = perfect mirror).
+    Metal { albedo: Vec3, fuzz: f32 },
+    /// Glass-like refraction with Schlick reflectance for the
+    /// reflect/refract split.
+    Dielectric { refraction_index: f32 },
+    /// Emits `color` directly and does not scatter further, e.g. the
+    /// accretion disk or an MTL material with a nonzero `Ke`.
+    Emissive { color: Vec3 },
+}
+
+/// The outcome of [`Material::scatter`].
+pub enum Scatter {
+    /// The ray continues in `direction`, attenuated by `attenuation`.
+    Bounce { direction: Vec3, attenuation: Vec3 },
+    /// The ray terminates here, contributing `color` to the radiance.
+    Emit(Vec3),
+    /// The ray terminates here, contributing nothing.
+    Absorb,
+}
+
+impl Material {
+    /// Scatters `ray_dir` off a surface with the given outward `normal`.
+    pub fn scatter(&self, ray_dir: Vec3, normal: Vec3, rng: &mut impl Rng) -> Scatter {
+        match *self {
+            Material::Lambertian { albedo } => {
+                let mut direction = normal + random_unit_vector(rng);
+                if direction.length_squared() < 1e-12 {
+                    direction = normal;
+                }
+                Scatter::Bounce {
+                    direction: direction.normalize(),
+                    attenuation: albedo,
+                }
+            }
+            Material::Metal { albedo, fuzz } => {
+                let reflected = reflect(ray_dir.normalize(), normal);
+                let direction = (reflected + fuzz * random_in_unit_sphere(rng)).normalize();
+                if direction.dot(normal) > 0.0 {
+                    Scatter::Bounce {
+                        direction,
+                        attenuation: albedo,
+                    }
+                } else {
+                    Scatter::Absorb
+                }
+            }
+            Material::Dielectric { refraction_index } => {
+                let unit_dir = ray_dir.normalize();
+                let front_face = unit_dir.dot(normal) < 0.0;
+                let (n, eta) = if front_face {
+                    (normal, 1.0 / refraction_index)
+                } else {
+                    (-normal, refraction_index)
+                };
+
+                let cos_theta = (-unit_dir.dot(n)).min(1.0);
+                let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+                let cannot_refract = eta * sin_theta > 1.0;
+
+                let direction = if cannot_refract || schlick_reflectance(cos_theta, eta) > rng.gen::<f32>() {
+                    reflect(unit_dir, n)
+                } else {
+                    refract(unit_dir, n, eta)
+                };
+
+                Scatter::Bounce {
+                    direction,
+                    attenuation: Vec3::ONE,
+                }
+            }
+            Material::Emissive { color } => Scatter::Emit(color),
+        }
+    }
+}
+
+fn reflect(d: Vec3, n: Vec3) -> Vec3 {
+    d - 2.0 * d.dot(n) * n
+}
+
+fn refract(uv: Vec3, n: Vec3, etai_over_etat: f32) -> Vec3 {
+    let cos_theta = (-uv.dot(n)).min(1.0);
+    let r_out_perp = etai_over_etat * (uv + cos_theta * n);
+    let r_out_parallel = -((1.0 - r_out_perp.length_squared()).abs().sqrt()) * n;
+    r_out_perp + r_out_parallel
+}
+
+/// Schlick's approximation: `r0 + (1-r0)(1-cosθ)^5`, `r0=((1-η)/(1+η))²`.
+fn schlick_reflectance(cosine: f32, refraction_index: f32) -> f32 {
+    let r0 = ((1.0 - refraction_index) / (1.0 + refraction_index)).powi(2);
+    r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+}
+
+/// A uniformly random unit vector, for Lambertian scattering.
+pub fn random_unit_vector(rng: &mut impl Rng) -> Vec3 {
+    loop {
+        let p = random_in_unit_sphere(rng);
+        let len_sq = p.length_squared();
+        if len_sq > 1e-8 {
+            return p / len_sq.sqrt();
+        }
+    }
+}
+
+/// A uniformly random point inside the unit sphere, for metal fuzz.
+pub fn random_in_unit_sphere(rng: &mut impl Rng) -> Vec3 {
+    loop {
+        let p = Vec3::new(
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+        );
+        if p.length_squared() <= 1.0 {
+            return p;
+        }
+    }
+}
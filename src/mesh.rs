@@ -0,0 +1,202 @@
+use glam::Vec3;
+
+use crate::bvh::Bvh;
+use crate::hittable::{Hit, Hittable};
+use crate::material::Material;
+
+/// A single triangle of a loaded mesh.
+pub struct Triangle {
+    pub v0: Vec3,
+    pub v1: Vec3,
+    pub v2: Vec3,
+    pub material: Material,
+}
+
+impl Triangle {
+    pub fn centroid(&self) -> Vec3 {
+        (self.v0 + self.v1 + self.v2) / 3.0
+    }
+
+    /// Möller–Trumbore ray/triangle intersection. Returns `(t, normal)` if
+    /// the ray `origin + t * dir` hits within `(0, t_max]`.
+    pub fn intersect(&self, origin: Vec3, dir: Vec3, t_max: f32) -> Option<(f32, Vec3)> {
+        const EPSILON: f32 = 1e-7;
+
+        let edge1 = self.v1 - self.v0;
+        let edge2 = self.v2 - self.v0;
+        let h = dir.cross(edge2);
+        let a = edge1.dot(h);
+        if a.abs() < EPSILON {
+            return None;
+        }
+
+        let f = 1.0 / a;
+        let s = origin - self.v0;
+        let u = f * s.dot(h);
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = s.cross(edge1);
+        let v = f * dir.dot(q);
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = f * edge2.dot(q);
+        if t <= EPSILON || t > t_max {
+            return None;
+        }
+
+        let normal = edge1.cross(edge2).try_normalize().unwrap_or(Vec3::Y);
+        Some((t, normal))
+    }
+}
+
+/// A triangle mesh loaded from an OBJ/MTL pair, with a BVH over its
+/// triangles so the geodesic marcher can test each march segment cheaply.
+pub struct Mesh {
+    triangles: Vec<Triangle>,
+    bvh: Bvh,
+}
+
+impl Mesh {
+    /// Loads geometry from an `.obj` file (plus any referenced `.mtl`),
+    /// mapping `Kd`/`Ks`+`Ns`/`Ke` onto the renderer's [`Material`] system.
+    pub fn load(path: &str) -> Result<Self, String> {
+        let load_options = tobj::LoadOptions {
+            triangulate: true,
+            single_index: true,
+            ..Default::default()
+        };
+        let (models, materials) = tobj::load_obj(path, &load_options).map_err(|e| e.to_string())?;
+        let materials = materials.map_err(|e| e.to_string())?;
+
+        let mut triangles = Vec::new();
+        for model in &models {
+            let mesh = &model.mesh;
+            let material = mesh
+                .material_id
+                .and_then(|id| materials.get(id))
+                .map(material_from_mtl)
+                .unwrap_or(Material::Lambertian {
+                    albedo: Vec3::splat(0.8),
+                });
+
+            let position = |index: u32| -> Vec3 {
+                let i = index as usize * 3;
+                Vec3::new(
+                    mesh.positions[i],
+                    mesh.positions[i + 1],
+                    mesh.positions[i + 2],
+                )
+            };
+
+            for face in mesh.indices.chunks_exact(3) {
+                triangles.push(Triangle {
+                    v0: position(face[0]),
+                    v1: position(face[1]),
+                    v2: position(face[2]),
+                    material,
+                });
+            }
+        }
+
+        let bvh = Bvh::build(&triangles);
+        Ok(Self { triangles, bvh })
+    }
+}
+
+impl Hittable for Mesh {
+    fn hit_segment(&self, start: Vec3, end: Vec3) -> Option<Hit> {
+        let (t, triangle, normal) = self.bvh.intersect_segment(&self.triangles, start, end)?;
+        Some(Hit {
+            point: start + (end - start) * t,
+            normal,
+            material: triangle.material,
+        })
+    }
+}
+
+/// Maps MTL properties onto the renderer's material model: `Ke` (nonzero)
+/// becomes an emitter, `Ks`/`Ns` a fuzzy metal, otherwise a Lambertian
+/// diffuse from `Kd`.
+fn material_from_mtl(mtl: &tobj::Material) -> Material {
+    let emission = parse_vec3_param(mtl, "Ke");
+    if emission.length_squared() > 1e-6 {
+        return Material::Emissive { color: emission };
+    }
+
+    let specular = mtl.specular.map(Vec3::from).unwrap_or(Vec3::ZERO);
+    if specular.length_squared() > 1e-6 {
+        let shininess = mtl.shininess.unwrap_or(0.0).max(1.0);
+        let fuzz = (1.0 / shininess).clamp(0.0, 1.0);
+        return Material::Metal {
+            albedo: specular,
+            fuzz,
+        };
+    }
+
+    let diffuse = mtl.diffuse.map(Vec3::from).unwrap_or(Vec3::splat(0.8));
+    Material::Lambertian { albedo: diffuse }
+}
+
+/// `tobj` only exposes the handful of standard MTL fields it recognises by
+/// name; anything else (like `Ke`, on older `tobj` versions) lands in
+/// `unknown_param` as raw text, so parse it back out here.
+fn parse_vec3_param(mtl: &tobj::Material, key: &str) -> Vec3 {
+    mtl.unknown_param
+        .get(key)
+        .and_then(|raw| {
+            let values: Vec<f32> = raw.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+            match values.as_slice() {
+                [x, y, z] => Some(Vec3::new(*x, *y, *z)),
+                _ => None,
+            }
+        })
+        .unwrap_or(Vec3::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_triangle() -> Triangle {
+        Triangle {
+            v0: Vec3::new(-1.0, 0.0, -1.0),
+            v1: Vec3::new(1.0, 0.0, -1.0),
+            v2: Vec3::new(0.0, 0.0, 1.0),
+            material: Material::Lambertian {
+                albedo: Vec3::splat(0.8),
+            },
+        }
+    }
+
+    #[test]
+    fn ray_through_triangle_center_hits() {
+        let triangle = unit_triangle();
+        let origin = Vec3::new(0.0, 5.0, -0.3);
+        let dir = Vec3::new(0.0, -1.0, 0.0);
+        let hit = triangle.intersect(origin, dir, f32::MAX);
+        assert!(hit.is_some());
+        let (t, normal) = hit.unwrap();
+        assert!((t - 5.0).abs() < 1e-4);
+        assert!(normal.y.abs() > 0.99);
+    }
+
+    #[test]
+    fn ray_missing_triangle_is_none() {
+        let triangle = unit_triangle();
+        let origin = Vec3::new(10.0, 5.0, -0.3);
+        let dir = Vec3::new(0.0, -1.0, 0.0);
+        assert!(triangle.intersect(origin, dir, f32::MAX).is_none());
+    }
+
+    #[test]
+    fn hit_beyond_t_max_is_none() {
+        let triangle = unit_triangle();
+        let origin = Vec3::new(0.0, 5.0, -0.3);
+        let dir = Vec3::new(0.0, -1.0, 0.0);
+        assert!(triangle.intersect(origin, dir, 1.0).is_none());
+    }
+}
@@ -0,0 +1,140 @@
+use glam::Vec3;
+use rand::Rng;
+use rayon::prelude::*;
+
+use crate::geodesic::{GeodesicEnd, GeodesicTracer};
+use crate::hittable::Scene;
+use crate::material::Scatter;
+
+/// A camera ready to shoot rays; cheap to copy so each tile worker can hold
+/// its own.
+#[derive(Clone, Copy)]
+pub struct Camera {
+    pub position: Vec3,
+    pub forward: Vec3,
+    pub right: Vec3,
+    pub up: Vec3,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Camera {
+    /// Builds a camera-space ray direction for screen coordinates `u, v` in
+    /// `[-1, 1]`.
+    pub fn ray_dir(&self, u: f32, v: f32) -> Vec3 {
+        (self.forward + self.right * u - self.up * v).normalize()
+    }
+}
+
+/// Immutable per-frame configuration shared by reference across worker
+/// threads; nothing in here is mutated while rendering.
+pub struct RenderSettings<'a> {
+    pub camera: Camera,
+    pub tracer: &'a GeodesicTracer,
+    pub scene: &'a Scene<'a>,
+    pub samples_per_pixel: u32,
+    pub max_depth: u32,
+}
+
+/// Renders the whole frame in parallel, one tile of rows per rayon task,
+/// overwriting `radiance_out[y * width + x]` with the average of
+/// `samples_per_pixel` jittered, path-traced samples.
+///
+/// Apart from the random jitter, the output depends only on `settings`: it
+/// only ever writes into `radiance_out` and keeps nothing between calls, so
+/// it can be called again each frame (progressive mode).
+pub fn render_frame(settings: &RenderSettings, radiance_out: &mut [Vec3]) {
+    const TILE_ROWS: usize = 16;
+    let width = settings.camera.width;
+
+    radiance_out
+        .par_chunks_mut(width * TILE_ROWS)
+        .enumerate()
+        .for_each(|(tile_index, tile)| {
+            let first_row = tile_index * TILE_ROWS;
+            let mut rng = rand::thread_rng();
+            for (row_offset, row) in tile.chunks_mut(width).enumerate() {
+                let y = first_row + row_offset;
+                for (x, pixel) in row.iter_mut().enumerate() {
+                    *pixel = render_pixel(x, y, settings, &mut rng);
+                }
+            }
+        });
+}
+
+/// Traces `samples_per_pixel` jittered rays through one pixel and averages
+/// their radiance. Pure in `(x, y, settings)` apart from the caller-owned
+/// RNG, so it is safe to call from any thread.
+fn render_pixel(x: usize, y: usize, settings: &RenderSettings, rng: &mut impl Rng) -> Vec3 {
+    let mut radiance = Vec3::ZERO;
+    for _ in 0..settings.samples_per_pixel {
+        let su: f32 = rng.gen_range(0.0..1.0);
+        let sv: f32 = rng.gen_range(0.0..1.0);
+        let u = ((x as f32 + su) / settings.camera.width as f32) * 2.0 - 1.0;
+        let v = ((y as f32 + sv) / settings.camera.height as f32) * 2.0 - 1.0;
+        let ray_dir = settings.camera.ray_dir(u, v);
+        radiance += color_for_ray(
+            settings.camera.position,
+            ray_dir,
+            settings.scene,
+            settings.tracer,
+            settings.max_depth,
+            rng,
+        );
+    }
+    radiance / settings.samples_per_pixel as f32
+}
+
+/// Traces one path: marches the geodesic to the first surface hit, scatters
+/// off its material, and recurses up to `depth` bounces.
+fn color_for_ray(
+    cam_pos: Vec3,
+    ray_dir: Vec3,
+    scene: &Scene,
+    tracer: &GeodesicTracer,
+    depth: u32,
+    rng: &mut impl Rng,
+) -> Vec3 {
+    if depth == 0 {
+        return Vec3::ZERO;
+    }
+
+    let (path, end) = tracer.trace(cam_pos, ray_dir);
+
+    let mut segment_start = cam_pos;
+    for &point in &path {
+        if let Some(hit) = scene.hit_segment(segment_start, point) {
+            return match hit.material.scatter(ray_dir, hit.normal, rng) {
+                Scatter::Bounce {
+                    direction,
+                    attenuation,
+                } => attenuation * color_for_ray(hit.point, direction, scene, tracer, depth - 1, rng),
+                Scatter::Emit(color) => color,
+                Scatter::Absorb => Vec3::ZERO,
+            };
+        }
+        segment_start = point;
+    }
+
+    match end {
+        GeodesicEnd::Captured => Vec3::ZERO,
+        GeodesicEnd::Escaped => background_radiance(cam_pos, ray_dir, &path),
+    }
+}
+
+/// Radiance of the sky in the direction the photon finally departed along
+/// (approximated from the last two sampled geodesic points).
+fn background_radiance(cam_pos: Vec3, ray_dir: Vec3, path: &[Vec3]) -> Vec3 {
+    let final_dir = match path {
+        [.., second_last, last] => (*last - *second_last).try_normalize().unwrap_or(ray_dir),
+        [only] => (*only - cam_pos).try_normalize().unwrap_or(ray_dir),
+        [] => ray_dir,
+    };
+    let star_val = (final_dir.x.sin() * final_dir.z.sin()).abs().powf(10.0);
+    if star_val > 0.5 {
+        // Stars emit a little above 1.0 so they bloom like the disk.
+        Vec3::splat(2.0)
+    } else {
+        Vec3::ZERO
+    }
+}